@@ -26,36 +26,139 @@
 //!
 use std::collections::HashMap;
 use std::fmt::{self};
+use std::future::Future;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use derive_builder::Builder;
-use metriki_core::metrics::{Metric, StaticGauge};
+use metriki_core::metrics::{Histogram, Meter, Metric, StaticGauge};
 use metriki_core::MetricsSet;
 
 use tokio_metrics::{RuntimeMetrics, RuntimeMonitor, TaskMetrics, TaskMonitor};
 
+/// Default cadence at which the background sampler advances the
+/// `tokio-metrics` interval iterator, independent of how often
+/// `get_all` is scraped.
+const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Divides `numerator` by `denominator`, returning `0.0` instead of
+/// `NaN` when the denominator is zero.
+fn safe_ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+/// Lazily-created per-field `Meter`s shared between the background
+/// sampler (which marks them) and `get_all` (which reads them), used
+/// when a set is built with `counters_as_meters(true)`.
+///
+/// A `Meter` accumulates its own running total and derives 1m/5m/15m
+/// rates from it, so marking it with each interval's delta is enough
+/// to get a proper throughput signal instead of a raw point-in-time
+/// count.
+#[derive(Default)]
+struct CounterMeters {
+    meters: Mutex<HashMap<String, Arc<Meter>>>,
+}
+
+impl CounterMeters {
+    fn mark(&self, field: &str, delta: u64) {
+        let mut meters = self.meters.lock().unwrap();
+        meters
+            .entry(field.to_string())
+            .or_insert_with(Metric::meter)
+            .mark_n(delta);
+    }
+
+    fn metric(&self, field: &str) -> Option<Metric> {
+        self.meters
+            .lock()
+            .unwrap()
+            .get(field)
+            .map(|meter| meter.clone().into())
+    }
+}
+
 /// A MetricsSet works with tokio_metrics `TaskMonitor`.
 ///
+/// Sampling of the underlying `intervals()` iterator happens on a
+/// background Tokio task running every `sample_interval`, not on every
+/// call to [`MetricsSet::get_all`]. This makes `get_all` idempotent and
+/// keeps the reported window stable even when multiple reporters poll
+/// the same set. The background task is aborted when this set is
+/// dropped.
 #[derive(Builder)]
+#[builder(build_fn(name = "build_internal", private))]
 pub struct TokioTaskMetricsSet {
     #[builder(setter(into))]
     name: String,
     #[builder(setter(custom))]
     monitor: Arc<Mutex<dyn Iterator<Item = TaskMetrics> + Send>>,
+    #[builder(default = "DEFAULT_SAMPLE_INTERVAL")]
+    sample_interval: Duration,
+    /// When `true`, the `*_count` fields are reported as `Meter`s
+    /// (carrying 1m/5m/15m rates) instead of `StaticGauge`s holding the
+    /// raw per-interval delta.
+    #[builder(default = "false")]
+    counters_as_meters: bool,
+    /// When `true`, `mean_poll_duration`, `mean_scheduled_duration` and
+    /// `mean_first_poll_delay` are accumulated into `Histogram`s instead
+    /// of being reported as a lone `StaticGauge` of the newest
+    /// interval's value.
+    #[builder(default = "false")]
+    histogram_durations: bool,
+    #[builder(setter(skip), default = "Arc::new(Mutex::new(None))")]
+    snapshot: Arc<Mutex<Option<TaskMetrics>>>,
+    #[builder(setter(skip), default = "Arc::new(CounterMeters::default())")]
+    counter_meters: Arc<CounterMeters>,
+    #[builder(setter(skip), default = "Arc::new(TaskHistograms::default())")]
+    duration_histograms: Arc<TaskHistograms>,
+    /// Handle to the background sampler task spawned by `build`, kept
+    /// so it can be aborted once this set is dropped instead of
+    /// outliving it and continuing to advance `intervals()`.
+    #[builder(setter(skip), default = "None")]
+    sampler: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl fmt::Debug for TokioTaskMetricsSet {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         f.debug_struct("TokioTaskMetricsSet")
             .field("name", &self.name)
+            .field("sample_interval", &self.sample_interval)
             .finish()
     }
 }
 
+impl Drop for TokioTaskMetricsSet {
+    fn drop(&mut self) {
+        if let Some(sampler) = self.sampler.take() {
+            sampler.abort();
+        }
+    }
+}
+
 impl TokioTaskMetricsSet {
     pub fn name(&self) -> &String {
         &self.name
     }
+
+    /// Inserts a `*_count` field, as a `Meter` when
+    /// `counters_as_meters` is enabled, or as a `StaticGauge`
+    /// otherwise.
+    fn insert_count_metric(&self, result: &mut HashMap<String, Metric>, field: &str, value: u64) {
+        let key = format!("{}.{}", self.name, field);
+
+        if self.counters_as_meters {
+            if let Some(metric) = self.counter_meters.metric(field) {
+                result.insert(key, metric);
+            }
+        } else {
+            result.insert(key, Metric::gauge(Box::new(StaticGauge(value as f64))).into());
+        }
+    }
 }
 
 impl TokioTaskMetricsSetBuilder {
@@ -63,67 +166,174 @@ impl TokioTaskMetricsSetBuilder {
         self.monitor = Some(Arc::new(Mutex::new(monitor.intervals())));
         self
     }
+
+    /// Builds the set and spawns the background task that samples
+    /// `intervals()` every `sample_interval`. Must be called from
+    /// within a Tokio runtime.
+    pub fn build(&self) -> Result<TokioTaskMetricsSet, TokioTaskMetricsSetBuilderError> {
+        let mut set = self.build_internal()?;
+
+        let monitor = set.monitor.clone();
+        let snapshot = set.snapshot.clone();
+        let sample_interval = set.sample_interval;
+        let counters_as_meters = set.counters_as_meters;
+        let counter_meters = set.counter_meters.clone();
+        let histogram_durations = set.histogram_durations;
+        let duration_histograms = set.duration_histograms.clone();
+
+        set.sampler = Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(sample_interval);
+            loop {
+                ticker.tick().await;
+                let next = monitor.lock().unwrap().next();
+                if let Some(metrics) = next {
+                    if counters_as_meters {
+                        counter_meters.mark("first_poll_count", metrics.first_poll_count as u64);
+                        counter_meters
+                            .mark("instrumented_count", metrics.instrumented_count as u64);
+                        counter_meters.mark("dropped_count", metrics.dropped_count as u64);
+                        counter_meters.mark("total_poll_count", metrics.total_poll_count as u64);
+                        counter_meters
+                            .mark("total_idled_count", metrics.total_idled_count as u64);
+                        counter_meters
+                            .mark("total_scheduled_count", metrics.total_scheduled_count as u64);
+                        counter_meters
+                            .mark("total_slow_poll_count", metrics.total_slow_poll_count as u64);
+                        counter_meters
+                            .mark("total_fast_poll_count", metrics.total_fast_poll_count as u64);
+                    }
+                    if histogram_durations {
+                        // Recorded in nanoseconds, matching
+                        // `TokioInstrumentedTaskSet`'s histograms of the
+                        // same metric names, so the unit doesn't depend
+                        // on which set produced a given reading.
+                        duration_histograms.update(
+                            "mean_poll_duration",
+                            metrics.mean_poll_duration().as_nanos() as f64,
+                        );
+                        duration_histograms.update(
+                            "mean_scheduled_duration",
+                            metrics.mean_scheduled_duration().as_nanos() as f64,
+                        );
+                        duration_histograms.update(
+                            "mean_first_poll_delay",
+                            metrics.mean_first_poll_delay().as_nanos() as f64,
+                        );
+                    }
+                    *snapshot.lock().unwrap() = Some(metrics);
+                }
+            }
+        }));
+
+        Ok(set)
+    }
 }
 
 impl MetricsSet for TokioTaskMetricsSet {
     fn get_all(&self) -> HashMap<String, Metric> {
-        let metrics: TaskMetrics = self.monitor.lock().unwrap().next().unwrap();
+        let metrics: TaskMetrics = match self.snapshot.lock().unwrap().clone() {
+            Some(metrics) => metrics,
+            None => return HashMap::new(),
+        };
 
         let mut result = HashMap::new();
-        result.insert(
-            format!("{}.first_poll_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.first_poll_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "first_poll_count",
+            metrics.first_poll_count as u64,
         );
-        result.insert(
-            format!("{}.instrumented_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.instrumented_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "instrumented_count",
+            metrics.instrumented_count as u64,
         );
-        result.insert(
-            format!("{}.dropped_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.dropped_count as f64))).into(),
+        self.insert_count_metric(&mut result, "dropped_count", metrics.dropped_count as u64);
+        self.insert_count_metric(
+            &mut result,
+            "total_poll_count",
+            metrics.total_poll_count as u64,
         );
-        result.insert(
-            format!("{}.total_poll_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_poll_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_idled_count",
+            metrics.total_idled_count as u64,
         );
-        result.insert(
-            format!("{}.total_idled_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_idled_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_scheduled_count",
+            metrics.total_scheduled_count as u64,
         );
-        result.insert(
-            format!("{}.total_scheduled_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_scheduled_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_slow_poll_count",
+            metrics.total_slow_poll_count as u64,
         );
-        result.insert(
-            format!("{}.total_slow_poll_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_slow_poll_count as f64))).into(),
-        );
-        result.insert(
-            format!("{}.total_fast_poll_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_fast_poll_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_fast_poll_count",
+            metrics.total_fast_poll_count as u64,
         );
 
+        if self.histogram_durations {
+            for field in [
+                "mean_poll_duration",
+                "mean_scheduled_duration",
+                "mean_first_poll_delay",
+            ] {
+                if let Some(metric) = self.duration_histograms.metric(field) {
+                    result.insert(format!("{}.{}", self.name, field), metric);
+                }
+            }
+        } else {
+            result.insert(
+                format!("{}.mean_poll_duration", self.name),
+                Metric::gauge(Box::new(StaticGauge(
+                    metrics.mean_poll_duration().as_millis() as f64,
+                )))
+                .into(),
+            );
+
+            result.insert(
+                format!("{}.mean_first_poll_delay", self.name),
+                Metric::gauge(Box::new(StaticGauge(
+                    metrics.mean_first_poll_delay().as_millis() as f64,
+                )))
+                .into(),
+            );
+
+            result.insert(
+                format!("{}.mean_scheduled_duration", self.name),
+                Metric::gauge(Box::new(StaticGauge(
+                    metrics.mean_scheduled_duration().as_millis() as f64,
+                )))
+                .into(),
+            );
+        }
+
         result.insert(
-            format!("{}.mean_poll_duration", self.name),
-            Metric::gauge(Box::new(StaticGauge(
-                metrics.mean_poll_duration().as_millis() as f64,
-            )))
+            format!("{}.slow_poll_ratio", self.name),
+            Metric::gauge(Box::new(StaticGauge(safe_ratio(
+                metrics.total_slow_poll_count as f64,
+                metrics.total_poll_count as f64,
+            ))))
             .into(),
         );
 
         result.insert(
-            format!("{}.mean_first_poll_delay", self.name),
-            Metric::gauge(Box::new(StaticGauge(
-                metrics.mean_first_poll_delay().as_millis() as f64,
-            )))
+            format!("{}.scheduled_ratio", self.name),
+            Metric::gauge(Box::new(StaticGauge(safe_ratio(
+                metrics.total_scheduled_count as f64,
+                (metrics.total_poll_count + metrics.total_scheduled_count) as f64,
+            ))))
             .into(),
         );
 
         result.insert(
-            format!("{}.mean_scheduled_duration", self.name),
-            Metric::gauge(Box::new(StaticGauge(
-                metrics.mean_scheduled_duration().as_millis() as f64,
-            )))
+            format!("{}.first_poll_delay_fraction", self.name),
+            Metric::gauge(Box::new(StaticGauge(safe_ratio(
+                metrics.total_first_poll_delay.as_nanos() as f64,
+                (metrics.total_first_poll_delay + metrics.total_poll_duration).as_nanos() as f64,
+            ))))
             .into(),
         );
 
@@ -133,13 +343,36 @@ impl MetricsSet for TokioTaskMetricsSet {
 
 /// A MetricsSet works with tokio_metrics `TaskMonitor`.
 ///
+/// Sampling of the underlying `intervals()` iterator happens on a
+/// background Tokio task running every `sample_interval`, not on every
+/// call to [`MetricsSet::get_all`]. This makes `get_all` idempotent and
+/// keeps the reported window stable even when multiple reporters poll
+/// the same set. The background task is aborted when this set is
+/// dropped.
 #[cfg(feature = "rt")]
 #[derive(Builder)]
+#[builder(build_fn(name = "build_internal", private))]
 pub struct TokioRuntimeMetricsSet {
     #[builder(setter(into))]
     name: String,
     #[builder(setter(custom))]
     monitor: Arc<Mutex<dyn Iterator<Item = RuntimeMetrics> + Send>>,
+    #[builder(default = "DEFAULT_SAMPLE_INTERVAL")]
+    sample_interval: Duration,
+    /// When `true`, the `*_count` fields are reported as `Meter`s
+    /// (carrying 1m/5m/15m rates) instead of `StaticGauge`s holding the
+    /// raw per-interval delta.
+    #[builder(default = "false")]
+    counters_as_meters: bool,
+    #[builder(setter(skip), default = "Arc::new(Mutex::new(None))")]
+    snapshot: Arc<Mutex<Option<RuntimeMetrics>>>,
+    #[builder(setter(skip), default = "Arc::new(CounterMeters::default())")]
+    counter_meters: Arc<CounterMeters>,
+    /// Handle to the background sampler task spawned by `build`, kept
+    /// so it can be aborted once this set is dropped instead of
+    /// outliving it and continuing to advance `intervals()`.
+    #[builder(setter(skip), default = "None")]
+    sampler: Option<tokio::task::JoinHandle<()>>,
 }
 
 #[cfg(feature = "rt")]
@@ -148,6 +381,47 @@ impl TokioRuntimeMetricsSetBuilder {
         self.monitor = Some(Arc::new(Mutex::new(monitor.intervals())));
         self
     }
+
+    /// Builds the set and spawns the background task that samples
+    /// `intervals()` every `sample_interval`. Must be called from
+    /// within a Tokio runtime.
+    pub fn build(&self) -> Result<TokioRuntimeMetricsSet, TokioRuntimeMetricsSetBuilderError> {
+        let mut set = self.build_internal()?;
+
+        let monitor = set.monitor.clone();
+        let snapshot = set.snapshot.clone();
+        let sample_interval = set.sample_interval;
+        let counters_as_meters = set.counters_as_meters;
+        let counter_meters = set.counter_meters.clone();
+
+        set.sampler = Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(sample_interval);
+            loop {
+                ticker.tick().await;
+                let next = monitor.lock().unwrap().next();
+                if let Some(metrics) = next {
+                    if counters_as_meters {
+                        counter_meters
+                            .mark("total_polls_count", metrics.total_polls_count as u64);
+                        counter_meters.mark("total_steal_count", metrics.total_steal_count as u64);
+                        counter_meters.mark("total_park_count", metrics.total_park_count as u64);
+                        counter_meters
+                            .mark("num_remote_schedules", metrics.num_remote_schedules as u64);
+                        counter_meters.mark(
+                            "total_local_schedule_count",
+                            metrics.total_local_schedule_count as u64,
+                        );
+                        counter_meters
+                            .mark("total_overflow_count", metrics.total_overflow_count as u64);
+                        counter_meters.mark("total_noop_count", metrics.total_noop_count as u64);
+                    }
+                    *snapshot.lock().unwrap() = Some(metrics);
+                }
+            }
+        }));
+
+        Ok(set)
+    }
 }
 
 #[cfg(feature = "rt")]
@@ -155,53 +429,85 @@ impl fmt::Debug for TokioRuntimeMetricsSet {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         f.debug_struct("TokioRuntimeMetricsSet")
             .field("name", &self.name)
+            .field("sample_interval", &self.sample_interval)
             .finish()
     }
 }
 
+#[cfg(feature = "rt")]
+impl Drop for TokioRuntimeMetricsSet {
+    fn drop(&mut self) {
+        if let Some(sampler) = self.sampler.take() {
+            sampler.abort();
+        }
+    }
+}
+
 #[cfg(feature = "rt")]
 impl TokioRuntimeMetricsSet {
     pub fn name(&self) -> &String {
         &self.name
     }
+
+    /// Inserts a `*_count` field, as a `Meter` when
+    /// `counters_as_meters` is enabled, or as a `StaticGauge`
+    /// otherwise.
+    fn insert_count_metric(&self, result: &mut HashMap<String, Metric>, field: &str, value: u64) {
+        let key = format!("{}.{}", self.name, field);
+
+        if self.counters_as_meters {
+            if let Some(metric) = self.counter_meters.metric(field) {
+                result.insert(key, metric);
+            }
+        } else {
+            result.insert(key, Metric::gauge(Box::new(StaticGauge(value as f64))).into());
+        }
+    }
 }
 
 #[cfg(feature = "rt")]
 impl MetricsSet for TokioRuntimeMetricsSet {
     fn get_all(&self) -> HashMap<String, Metric> {
-        let metrics: RuntimeMetrics = self.monitor.lock().unwrap().next().unwrap();
+        let metrics: RuntimeMetrics = match self.snapshot.lock().unwrap().clone() {
+            Some(metrics) => metrics,
+            None => return HashMap::new(),
+        };
 
         let mut result = HashMap::new();
-        result.insert(
-            format!("{}.total_polls_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_polls_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_polls_count",
+            metrics.total_polls_count as u64,
         );
-        result.insert(
-            format!("{}.total_steal_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_steal_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_steal_count",
+            metrics.total_steal_count as u64,
         );
-        result.insert(
-            format!("{}.total_park_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_park_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_park_count",
+            metrics.total_park_count as u64,
         );
-        result.insert(
-            format!("{}.num_remote_schedules", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.num_remote_schedules as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "num_remote_schedules",
+            metrics.num_remote_schedules as u64,
         );
-        result.insert(
-            format!("{}.total_local_schedule_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(
-                metrics.total_local_schedule_count as f64,
-            )))
-            .into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_local_schedule_count",
+            metrics.total_local_schedule_count as u64,
         );
-        result.insert(
-            format!("{}.total_overflow_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_overflow_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_overflow_count",
+            metrics.total_overflow_count as u64,
         );
-        result.insert(
-            format!("{}.total_noop_count", self.name),
-            Metric::gauge(Box::new(StaticGauge(metrics.total_noop_count as f64))).into(),
+        self.insert_count_metric(
+            &mut result,
+            "total_noop_count",
+            metrics.total_noop_count as u64,
         );
 
         result.insert(
@@ -220,3 +526,144 @@ impl MetricsSet for TokioRuntimeMetricsSet {
         result
     }
 }
+
+/// Named per-field `Histogram`s shared between the code that updates
+/// them and `get_all` (which reads them). [`TokioInstrumentedTaskSet::instrument`]
+/// updates them once per instrumented future completion; the
+/// [`TokioTaskMetricsSet`] background sampler updates them once per
+/// interval sample when `histogram_durations` is enabled.
+#[derive(Default)]
+struct TaskHistograms {
+    histograms: Mutex<HashMap<String, Arc<Histogram>>>,
+}
+
+impl TaskHistograms {
+    fn update(&self, field: &str, value: f64) {
+        let mut histograms = self.histograms.lock().unwrap();
+        histograms
+            .entry(field.to_string())
+            .or_insert_with(Metric::histogram)
+            .update(value as u64);
+    }
+
+    fn metric(&self, field: &str) -> Option<Metric> {
+        self.histograms
+            .lock()
+            .unwrap()
+            .get(field)
+            .map(|histogram| histogram.clone().into())
+    }
+}
+
+/// Divides `duration` by `count`, guarding against a zero count, and
+/// returns the mean in nanoseconds.
+fn mean_nanos(duration: Duration, count: u64) -> f64 {
+    safe_ratio(duration.as_nanos() as f64, count as f64)
+}
+
+/// A `MetricsSet` for per-future task instrumentation.
+///
+/// Where [`TokioTaskMetricsSet`] reports an aggregate interval sample
+/// shared by every task tracked by a `TaskMonitor`, this wraps a
+/// `TaskMonitor` and records the metrics attributable to a single call
+/// to [`instrument`](Self::instrument) into `metriki_core` `Histogram`s,
+/// giving percentile-bearing latency distributions (p50/p99) for poll
+/// and scheduling latency, rather than only a lifetime mean.
+///
+/// `TaskMonitor::cumulative()` is monitor-wide, not per-future, so the
+/// before/after delta `instrument` records is only accurate for one
+/// in-flight instrumented future at a time. Awaiting two futures
+/// instrumented concurrently through the same `TokioInstrumentedTaskSet`
+/// (e.g. via `tokio::join!` or separate spawns sharing this set) mixes
+/// their deltas together. Give each concurrent call site its own
+/// `TaskMonitor`/`TokioInstrumentedTaskSet` if you need isolation.
+#[derive(Clone)]
+pub struct TokioInstrumentedTaskSet {
+    name: String,
+    monitor: Arc<TaskMonitor>,
+    histograms: Arc<TaskHistograms>,
+}
+
+impl TokioInstrumentedTaskSet {
+    pub fn new<S: Into<String>>(name: S, monitor: TaskMonitor) -> Self {
+        TokioInstrumentedTaskSet {
+            name: name.into(),
+            monitor: Arc::new(monitor),
+            histograms: Arc::new(TaskHistograms::default()),
+        }
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Instruments `fut` so that, once it completes, the
+    /// `TaskMonitor::cumulative()` delta since it started is recorded
+    /// into this set's histograms. Only call this on one future at a
+    /// time per set — see the struct docs for why concurrent in-flight
+    /// calls cross-contaminate each other's delta.
+    pub fn instrument<F: Future>(&self, fut: F) -> impl Future<Output = F::Output> {
+        let monitor = self.monitor.clone();
+        let histograms = self.histograms.clone();
+        let before = monitor.cumulative();
+        let instrumented = monitor.instrument(fut);
+
+        async move {
+            let output = instrumented.await;
+            let after = monitor.cumulative();
+
+            let poll_duration =
+                after.total_poll_duration.saturating_sub(before.total_poll_duration);
+            let poll_count = after.total_poll_count.saturating_sub(before.total_poll_count);
+            histograms.update("mean_poll_duration", mean_nanos(poll_duration, poll_count));
+
+            let scheduled_duration = after
+                .total_scheduled_duration
+                .saturating_sub(before.total_scheduled_duration);
+            let scheduled_count = after
+                .total_scheduled_count
+                .saturating_sub(before.total_scheduled_count);
+            histograms.update(
+                "mean_scheduled_duration",
+                mean_nanos(scheduled_duration, scheduled_count),
+            );
+
+            let first_poll_delay = after
+                .total_first_poll_delay
+                .saturating_sub(before.total_first_poll_delay);
+            let first_poll_count = after.first_poll_count.saturating_sub(before.first_poll_count);
+            histograms.update(
+                "mean_first_poll_delay",
+                mean_nanos(first_poll_delay, first_poll_count),
+            );
+
+            output
+        }
+    }
+}
+
+impl fmt::Debug for TokioInstrumentedTaskSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("TokioInstrumentedTaskSet")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl MetricsSet for TokioInstrumentedTaskSet {
+    fn get_all(&self) -> HashMap<String, Metric> {
+        let mut result = HashMap::new();
+
+        for field in [
+            "mean_poll_duration",
+            "mean_scheduled_duration",
+            "mean_first_poll_delay",
+        ] {
+            if let Some(metric) = self.histograms.metric(field) {
+                result.insert(format!("{}.{}", self.name, field), metric);
+            }
+        }
+
+        result
+    }
+}